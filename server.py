@@ -7,14 +7,19 @@ Sessions are in-memory only (no persistence needed - sessions are ephemeral).
 
 Usage:
     pip install fastapi uvicorn websockets
-    python server.py [--port 8001]
+    python server.py [--port 8001] [--heartbeat-interval 15] [--heartbeat-timeout 10]
 
-    Or with uvicorn directly:
-    uvicorn server:app --host 0.0.0.0 --port 8001 --reload
+    Heartbeat settings can also be given as FOG_VIZU_HEARTBEAT_INTERVAL and
+    FOG_VIZU_HEARTBEAT_TIMEOUT environment variables (command-line flags win).
+
+    Starting with `python server.py` is required for clients to receive the
+    server_shutdown notice on restart. Running the uvicorn CLI directly
+    (uvicorn server:app) works but skips that notice and the command-line flags.
 """
 
 import argparse
 import asyncio
+import os
 import random
 import string
 import time
@@ -29,10 +34,43 @@ app = FastAPI(title="Fog Gate Sync Server")
 # How long to keep orphan sessions (seconds)
 ORPHAN_SESSION_TTL = 300  # 5 minutes
 
-# Heartbeat settings
+
+def positive_int(value) -> int:
+    """Parse a strictly positive integer (argparse type)."""
+    number = int(value)
+    if number <= 0:
+        raise argparse.ArgumentTypeError(f"must be greater than 0, got {value}")
+    return number
+
+
+def env_seconds(name: str, default: int) -> int:
+    """Read a positive number of seconds from the environment, exiting cleanly if invalid."""
+    value = os.environ.get(name)
+    if value is None:
+        return default
+    try:
+        return positive_int(value)
+    except (ValueError, argparse.ArgumentTypeError) as e:
+        raise SystemExit(f"{name}: invalid value {value!r} ({e})")
+
+
+# Heartbeat settings, e.g. for reverse proxies that drop idle connections sooner
+# than the default interval. Both must be > 0: a zero timeout would drop idle
+# clients at the first check, before they could answer a ping.
 HEARTBEAT_INTERVAL = 15  # Send ping every 15 seconds
 HEARTBEAT_TIMEOUT = 10   # Wait 10 seconds for pong response
 
+if __name__ != "__main__":
+    # Under the uvicorn CLI the environment is the only way to tune them;
+    # `python server.py` reads it in main so command-line flags take precedence
+    HEARTBEAT_INTERVAL = env_seconds("FOG_VIZU_HEARTBEAT_INTERVAL", HEARTBEAT_INTERVAL)
+    HEARTBEAT_TIMEOUT = env_seconds("FOG_VIZU_HEARTBEAT_TIMEOUT", HEARTBEAT_TIMEOUT)
+
+# Shutdown notice sent to every client before the server stops
+SHUTDOWN_REASON = "Server is restarting"
+SHUTDOWN_RETRY_AFTER = 5    # Seconds clients should wait before reconnecting
+SHUTDOWN_NOTICE_TIMEOUT = 2  # Max seconds spent delivering the notice
+
 
 # =============================================================================
 # Session Management
@@ -79,6 +117,28 @@ async def cleanup_orphan_sessions():
                         pass
 
 
+async def notify_shutdown():
+    """Tell every connected host and viewer that the server is going away."""
+    message = {
+        "type": "server_shutdown",
+        "reason": SHUTDOWN_REASON,
+        "retry_after": SHUTDOWN_RETRY_AFTER
+    }
+    # Snapshot: new connections can still change sessions while we await
+    clients = []
+    for session in list(sessions.values()):
+        if session.host:
+            clients.append(session.host)
+        clients.extend(session.viewers)
+
+    # Send concurrently and bound the total time so a stalled client can't hold up shutdown
+    sends = asyncio.gather(*(client.send_json(message) for client in clients), return_exceptions=True)
+    try:
+        await asyncio.wait_for(sends, timeout=SHUTDOWN_NOTICE_TIMEOUT)
+    except asyncio.TimeoutError:
+        print("Timed out delivering shutdown notice to some clients")
+
+
 @app.on_event("startup")
 async def startup():
     asyncio.create_task(cleanup_orphan_sessions())
@@ -97,7 +157,8 @@ async def handle_host_session(websocket: WebSocket, session: Session, is_resume:
     # Send confirmation to host
     await websocket.send_json({
         "type": "session_resumed" if is_resume else "session_created",
-        "code": session.code
+        "code": session.code,
+        "heartbeat_interval": HEARTBEAT_INTERVAL
     })
 
     # If resuming, notify viewers that host is back
@@ -228,7 +289,8 @@ async def viewer_connect(websocket: WebSocket, code: str):
     await websocket.send_json({
         "type": "connected",
         "state": session.state,
-        "host_connected": session.host is not None
+        "host_connected": session.host is not None,
+        "heartbeat_interval": HEARTBEAT_INTERVAL
     })
 
     async def heartbeat_task():
@@ -289,9 +351,32 @@ if __name__ == "__main__":
     parser = argparse.ArgumentParser(description="Fog Gate Sync Server")
     parser.add_argument("--port", type=int, default=8001, help="Port to listen on")
     parser.add_argument("--host", default="0.0.0.0", help="Host to bind to")
+    parser.add_argument("--heartbeat-interval", type=positive_int,
+                        help="Seconds between server pings "
+                             f"(default: $FOG_VIZU_HEARTBEAT_INTERVAL or {HEARTBEAT_INTERVAL})")
+    parser.add_argument("--heartbeat-timeout", type=positive_int,
+                        help="Extra seconds to wait for a pong before dropping a client "
+                             f"(default: $FOG_VIZU_HEARTBEAT_TIMEOUT or {HEARTBEAT_TIMEOUT})")
     args = parser.parse_args()
 
+    # Flags win; the environment is only consulted (and validated) when a flag is omitted
+    if args.heartbeat_interval is not None:
+        HEARTBEAT_INTERVAL = args.heartbeat_interval
+    else:
+        HEARTBEAT_INTERVAL = env_seconds("FOG_VIZU_HEARTBEAT_INTERVAL", HEARTBEAT_INTERVAL)
+    if args.heartbeat_timeout is not None:
+        HEARTBEAT_TIMEOUT = args.heartbeat_timeout
+    else:
+        HEARTBEAT_TIMEOUT = env_seconds("FOG_VIZU_HEARTBEAT_TIMEOUT", HEARTBEAT_TIMEOUT)
+
     print(f"Starting server on http://{args.host}:{args.port}")
     print(f"Open http://localhost:{args.port} in your browser")
 
-    uvicorn.run(app, host=args.host, port=args.port)
+    class NotifyingServer(uvicorn.Server):
+        """Uvicorn server that sends a shutdown notice before closing WebSockets."""
+
+        async def shutdown(self, sockets=None):
+            await notify_shutdown()
+            await super().shutdown(sockets=sockets)
+
+    NotifyingServer(uvicorn.Config(app, host=args.host, port=args.port)).run()
@@ -0,0 +1,201 @@
+// ============================================================
+// EXPORT - Graph export to DOT (Graphviz) and GraphML (Gephi)
+// ============================================================
+
+import * as State from './state.js';
+import * as Toast from './toast.js';
+
+// ============================================================
+// GRAPH SELECTION
+// ============================================================
+
+/**
+ * Collect the nodes and links to export
+ * In exploration mode, only discovered areas and traversed links are exported
+ * (no spoilers). In full spoiler mode, the whole graph is exported.
+ * @returns {{nodes: Array, links: Array}|null}
+ */
+function getExportGraph() {
+    const graphData = State.getGraphData();
+    if (!graphData) return null;
+
+    const explorationState = State.getExplorationState();
+    if (!State.isExplorationMode() || !explorationState) {
+        return { nodes: graphData.nodes, links: graphData.links };
+    }
+
+    const nodes = graphData.nodes.filter(n => explorationState.discovered.has(n.id));
+    const links = graphData.links.filter(link => {
+        const sourceId = typeof link.source === 'object' ? link.source.id : link.source;
+        const targetId = typeof link.target === 'object' ? link.target.id : link.target;
+
+        if (!explorationState.discovered.has(sourceId) || !explorationState.discovered.has(targetId)) {
+            return false;
+        }
+        return State.isLinkDiscovered(sourceId, targetId) || State.isLinkDiscovered(targetId, sourceId);
+    });
+
+    return { nodes, links };
+}
+
+function linkEndpoints(link) {
+    return {
+        sourceId: typeof link.source === 'object' ? link.source.id : link.source,
+        targetId: typeof link.target === 'object' ? link.target.id : link.target
+    };
+}
+
+// ============================================================
+// DOT
+// ============================================================
+
+function escapeDot(str) {
+    return String(str).replace(/\\/g, '\\\\').replace(/"/g, '\\"');
+}
+
+/**
+ * Serialize a graph to Graphviz DOT
+ * Links are directed edges. Random links are listed once per pair in the spoiler log,
+ * so bidirectional ones get arrows on both ends; preexisting links list each direction.
+ */
+export function toDot({ nodes, links }, seed) {
+    const lines = [];
+    lines.push(`digraph "${escapeDot(seed ? `Seed ${seed}` : 'Fog Gate Randomizer')}" {`);
+    lines.push('    node [shape=box, style=rounded];');
+
+    nodes.forEach(n => {
+        const attrs = [`label="${escapeDot(n.id)}"`];
+        if (n.isBoss) attrs.push('color="#c04040"', 'penwidth=2');
+        if (n.id === State.START_NODE) attrs.push('shape=doubleoctagon');
+        if (n.scaling) attrs.push(`tooltip="${escapeDot(n.scaling)}"`);
+        lines.push(`    "${escapeDot(n.id)}" [${attrs.join(', ')}];`);
+    });
+
+    links.forEach(link => {
+        const { sourceId, targetId } = linkEndpoints(link);
+        const attrs = [`type="${link.type}"`];
+        if (link.type === 'random' && !link.oneWay) attrs.push('dir=both');
+        if (link.type === 'preexisting') attrs.push('style=dashed');
+        if (link.requiredItemFrom) attrs.push(`label="${escapeDot(link.requiredItemFrom)}"`);
+        lines.push(`    "${escapeDot(sourceId)}" -> "${escapeDot(targetId)}" [${attrs.join(', ')}];`);
+    });
+
+    lines.push('}');
+    return lines.join('\n') + '\n';
+}
+
+// ============================================================
+// GRAPHML
+// ============================================================
+
+function escapeXml(str) {
+    return String(str)
+        .replace(/&/g, '&amp;')
+        .replace(/</g, '&lt;')
+        .replace(/>/g, '&gt;')
+        .replace(/"/g, '&quot;');
+}
+
+/**
+ * Serialize a graph to GraphML
+ * Node and edge attributes are declared as GraphML keys so Gephi/yEd can filter on them
+ */
+export function toGraphML({ nodes, links }, seed) {
+    const lines = [];
+    lines.push('<?xml version="1.0" encoding="UTF-8"?>');
+    lines.push('<graphml xmlns="http://graphml.graphdrawing.org/xmlns">');
+    lines.push('  <key id="boss" for="node" attr.name="boss" attr.type="boolean"/>');
+    lines.push('  <key id="scaling" for="node" attr.name="scaling" attr.type="string"/>');
+    lines.push('  <key id="type" for="edge" attr.name="type" attr.type="string"/>');
+    lines.push('  <key id="oneWay" for="edge" attr.name="oneWay" attr.type="boolean"/>');
+    lines.push('  <key id="requiredItemFrom" for="edge" attr.name="requiredItemFrom" attr.type="string"/>');
+    lines.push(`  <graph id="${escapeXml(seed || 'fog-gates')}" edgedefault="directed">`);
+
+    nodes.forEach(n => {
+        lines.push(`    <node id="${escapeXml(n.id)}">`);
+        lines.push(`      <data key="boss">${n.isBoss ? 'true' : 'false'}</data>`);
+        if (n.scaling) lines.push(`      <data key="scaling">${escapeXml(n.scaling)}</data>`);
+        lines.push('    </node>');
+    });
+
+    links.forEach((link, i) => {
+        const { sourceId, targetId } = linkEndpoints(link);
+        lines.push(`    <edge id="e${i}" source="${escapeXml(sourceId)}" target="${escapeXml(targetId)}">`);
+        lines.push(`      <data key="type">${link.type}</data>`);
+        lines.push(`      <data key="oneWay">${link.oneWay ? 'true' : 'false'}</data>`);
+        if (link.requiredItemFrom) lines.push(`      <data key="requiredItemFrom">${escapeXml(link.requiredItemFrom)}</data>`);
+        lines.push('    </edge>');
+    });
+
+    lines.push('  </graph>');
+    lines.push('</graphml>');
+    return lines.join('\n') + '\n';
+}
+
+// ============================================================
+// DOWNLOAD
+// ============================================================
+
+const FORMATS = {
+    dot: { serialize: toDot, extension: 'dot', mimeType: 'text/vnd.graphviz' },
+    graphml: { serialize: toGraphML, extension: 'graphml', mimeType: 'application/graphml+xml' }
+};
+
+function downloadText(text, filename, mimeType) {
+    const blob = new Blob([text], { type: mimeType });
+    const url = URL.createObjectURL(blob);
+    const a = document.createElement('a');
+    a.href = url;
+    a.download = filename;
+    document.body.appendChild(a);
+    a.click();
+    a.remove();
+    URL.revokeObjectURL(url);
+}
+
+/**
+ * Export the current graph in the given format ('dot' or 'graphml')
+ */
+export function exportGraph(format) {
+    const fmt = FORMATS[format];
+    const graph = getExportGraph();
+    if (!fmt || !graph) return;
+
+    if (graph.nodes.length === 0) {
+        Toast.warning('Nothing to export yet');
+        return;
+    }
+
+    const seed = State.getSeed();
+    const scope = State.isExplorationMode() ? 'discovered' : 'full';
+    const filename = `fog-gates-${seed || 'unknown'}-${scope}.${fmt.extension}`;
+    downloadText(fmt.serialize(graph, seed), filename, fmt.mimeType);
+}
+
+// ============================================================
+// UI
+// ============================================================
+
+export function initExportUI() {
+    const exportBtn = document.getElementById('export-btn');
+    const exportMenu = document.getElementById('export-menu');
+    if (!exportBtn || !exportMenu) return;
+
+    exportBtn.addEventListener('click', (e) => {
+        e.stopPropagation();
+        exportMenu.classList.toggle('visible');
+    });
+
+    exportMenu.querySelectorAll('[data-format]').forEach(btn => {
+        btn.addEventListener('click', () => {
+            exportMenu.classList.remove('visible');
+            exportGraph(btn.getAttribute('data-format'));
+        });
+    });
+
+    document.addEventListener('click', (e) => {
+        if (!e.target.closest('#export-container')) {
+            exportMenu.classList.remove('visible');
+        }
+    });
+}
@@ -6,6 +6,7 @@ import * as State from './state.js';
 import * as UI from './ui.js';
 import * as Graph from './graph.js';
 import * as Sync from './sync.js';
+import * as Export from './export.js';
 
 // ============================================================
 // INITIALIZATION
@@ -23,6 +24,9 @@ async function init() {
 
     // Initialize stream modal UI
     Sync.initStreamUI();
+
+    // Initialize graph export menu
+    Export.initExportUI();
     
     // Subscribe to graph render events
     State.subscribe('graphNeedsRender', ({ preservePositions, centerOnNodeId }) => {
@@ -27,10 +27,13 @@ let lastSyncedViewport = null;
 let isRenderingGraph = false;
 let isSyncing = false;
 let currentSessionCode = null;  // Track session code for reconnection
+let shutdownReconnectDelay = null;  // Set when the server announced a shutdown
+let shutdownReason = null;
 
 // Client-side heartbeat monitoring (detect if server stops sending pings)
-const HEARTBEAT_EXPECTED_INTERVAL = 15000;  // Server sends ping every 15s
+const HEARTBEAT_DEFAULT_INTERVAL = 15000;   // Server sends ping every 15s unless it announces otherwise
 const HEARTBEAT_GRACE_PERIOD = 15000;       // Allow 15s extra before considering dead
+let heartbeatExpectedInterval = HEARTBEAT_DEFAULT_INTERVAL;
 let lastServerPing = null;
 let heartbeatCheckInterval = null;
 
@@ -58,7 +61,7 @@ function startHeartbeatMonitoring() {
         }
 
         const timeSinceLastPing = Date.now() - lastServerPing;
-        const timeout = HEARTBEAT_EXPECTED_INTERVAL + HEARTBEAT_GRACE_PERIOD;
+        const timeout = heartbeatExpectedInterval + HEARTBEAT_GRACE_PERIOD;
 
         if (timeSinceLastPing > timeout) {
             console.log(`No server ping for ${Math.round(timeSinceLastPing / 1000)}s, connection likely dead`);
@@ -80,6 +83,43 @@ function onServerPingReceived() {
     lastServerPing = Date.now();
 }
 
+// Server announces its ping interval (seconds) when a session is created/joined
+function applyServerHeartbeatInterval(data) {
+    heartbeatExpectedInterval = data.heartbeat_interval > 0
+        ? data.heartbeat_interval * 1000
+        : HEARTBEAT_DEFAULT_INTERVAL;
+}
+
+// =============================================================================
+// Server Shutdown Notice
+// =============================================================================
+
+// Server is going away (restart/deploy): warn the user and wait the announced
+// delay before the first reconnect attempt instead of hammering it
+function handleServerShutdown(data) {
+    shutdownReconnectDelay = data.retry_after > 0 ? data.retry_after * 1000 : RECONNECT_BASE_DELAY;
+    shutdownReason = data.reason || 'Server is shutting down';
+    const msg = formatShutdownMessage(shutdownReason, shutdownReconnectDelay);
+    console.log(msg);
+    if (isViewerMode) {
+        updateViewerStatus(msg);
+    } else {
+        Toast.warning(msg);
+        updateSyncStatus(msg);
+    }
+}
+
+// A live session must never reuse a notice from an earlier shutdown that
+// didn't end up closing the socket
+function clearShutdownNotice() {
+    shutdownReconnectDelay = null;
+    shutdownReason = null;
+}
+
+function formatShutdownMessage(reason, delay) {
+    return `${reason} - reconnecting in ${Math.round(delay / 1000)}s`;
+}
+
 // =============================================================================
 // Initialization
 // =============================================================================
@@ -143,7 +183,14 @@ export async function createSession() {
                 return;
             }
 
+            if (data.type === 'server_shutdown') {
+                handleServerShutdown(data);
+                return;
+            }
+
             if (data.type === 'session_created') {
+                applyServerHeartbeatInterval(data);
+                clearShutdownNotice();
                 currentSessionCode = data.code;
                 State.setSyncState(true, true, data.code);
                 showConnectedUI();
@@ -186,6 +233,11 @@ async function resumeHostSession(code) {
                 return;
             }
 
+            if (data.type === 'server_shutdown') {
+                handleServerShutdown(data);
+                return;
+            }
+
             if (data.type === 'error') {
                 console.log("Failed to resume session:", data.message);
                 ws.close();
@@ -194,6 +246,8 @@ async function resumeHostSession(code) {
             }
 
             if (data.type === 'session_resumed') {
+                applyServerHeartbeatInterval(data);
+                clearShutdownNotice();
                 reconnectAttempts = 0;
                 currentSessionCode = data.code;
                 State.setSyncState(true, true, data.code);
@@ -243,6 +297,11 @@ export async function joinSession(code, isReconnect = false) {
                 return;
             }
 
+            if (data.type === 'server_shutdown') {
+                handleServerShutdown(data);
+                return;
+            }
+
             if (data.type === 'error') {
                 if (!isReconnect) {
                     if (isViewerMode) {
@@ -263,6 +322,8 @@ export async function joinSession(code, isReconnect = false) {
             }
 
             if (data.type === 'connected') {
+                applyServerHeartbeatInterval(data);
+                clearShutdownNotice();
                 currentSessionCode = code;
                 State.setSyncState(true, false, code);
                 showConnectedUI();
@@ -350,12 +411,22 @@ async function handleDisconnect() {
         return;
     }
 
-    reconnectAttempts++;
-    // Exponential backoff with cap
-    const delay = Math.min(RECONNECT_BASE_DELAY * Math.pow(2, reconnectAttempts - 1), RECONNECT_MAX_DELAY);
+    let delay, reconnectMsg;
     const remainingTime = Math.round((MAX_RECONNECT_DURATION - elapsed) / 1000);
+    if (shutdownReconnectDelay !== null) {
+        // Announced shutdown: wait as requested, then restart backoff from scratch.
+        // Keep showing the server's reason (viewers in OBS have no toast).
+        delay = shutdownReconnectDelay;
+        reconnectMsg = formatShutdownMessage(shutdownReason, delay);
+        clearShutdownNotice();
+        reconnectAttempts = 0;
+    } else {
+        reconnectAttempts++;
+        // Exponential backoff with cap
+        delay = Math.min(RECONNECT_BASE_DELAY * Math.pow(2, reconnectAttempts - 1), RECONNECT_MAX_DELAY);
+        reconnectMsg = `Reconnecting... (${remainingTime}s remaining)`;
+    }
     console.log(`Attempting reconnect ${reconnectAttempts} in ${delay}ms (${remainingTime}s remaining)...`);
-    const reconnectMsg = `Reconnecting... (${remainingTime}s remaining)`;
     if (isViewerMode) {
         updateViewerStatus(reconnectMsg);
     } else {
@@ -396,6 +467,7 @@ export function disconnectSession() {
     reconnectStartTime = null;
     reconnectAttempts = 0;
     currentSessionCode = null;
+    clearShutdownNotice();
 
     if (ws) {
         ws.close();